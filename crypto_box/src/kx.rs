@@ -0,0 +1,102 @@
+//! Directional session key exchange, matching libsodium's `crypto_kx`.
+//!
+//! Sharing a single [`SalsaBox`](crate::SalsaBox)/[`ChaChaBox`](crate::ChaChaBox)
+//! key for both directions of a connection means both peers must be careful
+//! never to reuse a nonce across the two directions. This module instead
+//! derives two independent keys from one X25519 exchange — one for sending,
+//! one for receiving — so each peer can feed its `tx`/`rx` halves into their
+//! own [`SalsaBox`](crate::SalsaBox)/[`ChaChaBox`](crate::ChaChaBox) and pick
+//! nonces independently.
+//!
+//! The `kx` feature pulls in `blake2` on its own, independent of the `seal`
+//! feature: both hash with BLAKE2b, but `seal` uses the variable-output
+//! `VarBlake2b` for its 24-byte nonce while this module uses the fixed
+//! 64-byte `Blake2b` for session keys, and either feature can be enabled
+//! without the other.
+
+use crate::aead::Error;
+use crate::{PublicKey, SecretKey, KEY_SIZE};
+use blake2::{Blake2b, Digest};
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A directional pair of session keys produced by [`client_session_keys`] or
+/// [`server_session_keys`].
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct SessionKeys {
+    /// Key for decrypting messages received from the peer.
+    pub rx: [u8; KEY_SIZE],
+
+    /// Key for encrypting messages sent to the peer.
+    pub tx: [u8; KEY_SIZE],
+}
+
+/// Derive session keys for the client side of a key exchange, à la
+/// libsodium's `crypto_kx_client_session_keys`.
+pub fn client_session_keys(
+    client_sk: &SecretKey,
+    client_pk: &PublicKey,
+    server_pk: &PublicKey,
+) -> Result<SessionKeys, Error> {
+    let keys = derive(client_sk, server_pk, client_pk, server_pk)?;
+    Ok(SessionKeys {
+        rx: first_half(&keys),
+        tx: second_half(&keys),
+    })
+}
+
+/// Derive session keys for the server side of a key exchange, à la
+/// libsodium's `crypto_kx_server_session_keys`.
+pub fn server_session_keys(
+    server_sk: &SecretKey,
+    server_pk: &PublicKey,
+    client_pk: &PublicKey,
+) -> Result<SessionKeys, Error> {
+    let keys = derive(server_sk, client_pk, client_pk, server_pk)?;
+    Ok(SessionKeys {
+        rx: second_half(&keys),
+        tx: first_half(&keys),
+    })
+}
+
+/// `BLAKE2b-512(q || client_pk || server_pk)`, where `q` is the X25519
+/// shared secret between `sk` and `peer_pk`. `client_pk`/`server_pk` are
+/// always hashed in that fixed order so both peers land on the same output,
+/// regardless of which one is calling.
+///
+/// Like libsodium's `crypto_kx`, this rejects an all-zero shared secret,
+/// which a degenerate (e.g. small-order) peer public key can otherwise
+/// force, so callers don't silently end up with predictable session keys.
+fn derive(
+    sk: &SecretKey,
+    peer_pk: &PublicKey,
+    client_pk: &PublicKey,
+    server_pk: &PublicKey,
+) -> Result<[u8; 64], Error> {
+    let shared_secret = sk.diffie_hellman(peer_pk);
+    if shared_secret.iter().all(|&byte| byte == 0) {
+        return Err(Error);
+    }
+
+    let mut hasher = Blake2b::new();
+    hasher.update(shared_secret);
+    hasher.update(client_pk.as_bytes());
+    hasher.update(server_pk.as_bytes());
+
+    let mut keys = [0u8; 64];
+    keys.copy_from_slice(&hasher.finalize());
+    Ok(keys)
+}
+
+fn first_half(keys: &[u8; 64]) -> [u8; KEY_SIZE] {
+    let mut half = [0u8; KEY_SIZE];
+    half.copy_from_slice(&keys[..KEY_SIZE]);
+    half
+}
+
+fn second_half(keys: &[u8; 64]) -> [u8; KEY_SIZE] {
+    let mut half = [0u8; KEY_SIZE];
+    half.copy_from_slice(&keys[KEY_SIZE..]);
+    half
+}
@@ -114,6 +114,17 @@
 //!
 //! <https://docs.rs/xsalsa20poly1305/latest/xsalsa20poly1305/#in-place-usage-eliminates-alloc-requirement>
 //!
+//! ## Sealed Boxes (Anonymous Encryption)
+//!
+//! The optional `seal` feature adds [`SalsaBox::seal`] and
+//! [`SalsaBox::unseal`], a pure Rust implementation of libsodium's
+//! `crypto_box_seal` construction. A sealed box lets anyone encrypt a
+//! message to a recipient's [`PublicKey`] without holding (or even having)
+//! a sender keypair of their own: an ephemeral X25519 keypair is generated
+//! for the call, its public half is bundled with the ciphertext, and its
+//! secret half is discarded afterward. The recipient can open the message
+//! but learns nothing about who sent it.
+//!
 //! [NaCl]: https://nacl.cr.yp.to/
 //! [`crypto_box`]: https://nacl.cr.yp.to/box.html
 //! [X25519]: https://cr.yp.to/ecdh.html
@@ -128,6 +139,16 @@
 #![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
 #![warn(missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "kx")]
+pub mod kx;
+
+/// X25519 public key.
+///
+/// Note: [`PublicKey`] is a re-export from `x25519-dalek`, so Rust's orphan
+/// rules block a direct `impl subtle::ConstantTimeEq for PublicKey` here
+/// (both the trait and the type are foreign to this crate). Use
+/// [`PublicKeyBytes`] (behind the `subtle` feature) when you need a
+/// constant-time comparison on a `PublicKey` directly.
 pub use x25519_dalek::PublicKey;
 pub use xsalsa20poly1305::aead;
 
@@ -136,14 +157,35 @@ use aead::generic_array::{
     GenericArray,
 };
 use aead::{Aead, Buffer, Error, NewAead};
+use chacha20::hchacha20;
 use core::fmt::{self, Debug};
 use rand_core::{CryptoRng, RngCore};
 use salsa20::hsalsa20;
+use sha2::{Digest, Sha512};
+use xchacha20poly1305::XChaCha20Poly1305;
 use xsalsa20poly1305::{Tag, XSalsa20Poly1305};
 
+#[cfg(feature = "seal")]
+extern crate alloc;
+#[cfg(feature = "seal")]
+use alloc::vec::Vec;
+#[cfg(feature = "seal")]
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+#[cfg(any(feature = "zeroize", feature = "subtle"))]
+use subtle::ConstantTimeEq;
+#[cfg(any(feature = "zeroize", feature = "seal"))]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
 /// Size of a `crypto_box` public or secret key in bytes.
 pub const KEY_SIZE: usize = 32;
 
+/// Size of a [`SalsaBox`]/[`ChaChaBox`] Poly1305 authentication tag in bytes.
+#[cfg(feature = "seal")]
+const TAG_SIZE: usize = 16;
+
 /// Generate a random nonce: every message MUST have a unique nonce!
 ///
 /// Do *NOT* ever reuse the same nonce for two messages!
@@ -157,7 +199,12 @@ where
 }
 
 /// `crypto_box` secret key
+///
+/// Zeroized on drop whenever the `zeroize` feature is enabled, and also
+/// whenever `seal` is, since [`SalsaBox::seal`] generates an ephemeral
+/// [`SecretKey`] per call that must not linger in memory afterward.
 #[derive(Clone)]
+#[cfg_attr(any(feature = "zeroize", feature = "seal"), derive(Zeroize, ZeroizeOnDrop))]
 pub struct SecretKey(x25519_dalek::StaticSecret);
 
 impl SecretKey {
@@ -178,6 +225,15 @@ impl SecretKey {
     pub fn to_bytes(&self) -> [u8; KEY_SIZE] {
         self.0.to_bytes()
     }
+
+    /// Compute the raw X25519 shared secret with `their_public`.
+    ///
+    /// This is a crate-internal building block for the [`kx`](crate::kx)
+    /// module. The result is *not* suitable for use as an encryption key on
+    /// its own, since a raw X25519 output isn't uniformly random.
+    pub(crate) fn diffie_hellman(&self, their_public: &PublicKey) -> [u8; KEY_SIZE] {
+        *self.0.diffie_hellman(their_public).as_bytes()
+    }
 }
 
 impl From<[u8; KEY_SIZE]> for SecretKey {
@@ -198,6 +254,104 @@ impl From<&SecretKey> for PublicKey {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl ConstantTimeEq for SecretKey {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        let mut a = self.to_bytes();
+        let mut b = other.to_bytes();
+        let result = a.ct_eq(&b);
+        a.zeroize();
+        b.zeroize();
+        result
+    }
+}
+
+/// A [`PublicKey`]'s serialized bytes, for constant-time comparison.
+///
+/// See the note on the [`PublicKey`] re-export for why this newtype exists.
+#[cfg(feature = "subtle")]
+#[derive(Clone, Copy)]
+pub struct PublicKeyBytes([u8; KEY_SIZE]);
+
+#[cfg(feature = "subtle")]
+impl From<&PublicKey> for PublicKeyBytes {
+    fn from(public_key: &PublicKey) -> Self {
+        PublicKeyBytes(*public_key.as_bytes())
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl ConstantTimeEq for PublicKeyBytes {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl PartialEq for PublicKeyBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl Eq for PublicKeyBytes {}
+
+/// An X25519 [`PublicKey`]/[`SecretKey`] pair.
+///
+/// Bundles a keypair together so callers don't need to re-derive the
+/// public key from the secret one. [`KeyPair::from_seed`] additionally
+/// supports deterministic generation, mirroring libsodium's
+/// `crypto_box_seed_keypair`, which is useful for reproducible test
+/// vectors or HD-style key derivation.
+pub struct KeyPair {
+    /// This keypair's public half.
+    pub public_key: PublicKey,
+
+    /// This keypair's secret half.
+    pub secret_key: SecretKey,
+}
+
+impl KeyPair {
+    /// Generate a random [`KeyPair`].
+    pub fn generate<T>(csprng: &mut T) -> Self
+    where
+        T: RngCore + CryptoRng,
+    {
+        let secret_key = SecretKey::generate(csprng);
+        let public_key = secret_key.public_key();
+        KeyPair {
+            public_key,
+            secret_key,
+        }
+    }
+
+    /// Deterministically derive a [`KeyPair`] from a 32-byte seed, matching
+    /// libsodium's `crypto_box_seed_keypair`: the seed is hashed with
+    /// SHA-512 and the first 32 bytes of the digest become the X25519
+    /// secret scalar, so a given seed always yields the same keypair.
+    ///
+    /// The resulting [`PublicKey`] reproduces libsodium/dryoc seed keypair
+    /// test vectors, but [`SecretKey::to_bytes`] will *not*: libsodium
+    /// stores the raw, unclamped `SHA-512(seed)[..32]` as its secret key,
+    /// while [`SecretKey`] is backed by `x25519_dalek::StaticSecret`, which
+    /// clamps the scalar (per the X25519 spec) at construction time. Both
+    /// represent the same point on the curve and interoperate in Diffie-
+    /// Hellman, but their serialized secret-key bytes differ.
+    pub fn from_seed(seed: &[u8; KEY_SIZE]) -> Self {
+        let digest = Sha512::digest(seed);
+        let mut scalar = [0u8; KEY_SIZE];
+        scalar.copy_from_slice(&digest[..KEY_SIZE]);
+
+        let secret_key = SecretKey::from(scalar);
+        let public_key = secret_key.public_key();
+        KeyPair {
+            public_key,
+            secret_key,
+        }
+    }
+}
+
 /// Alias for [`SalsaBox`].
 pub type Box = SalsaBox;
 
@@ -229,6 +383,113 @@ impl SalsaBox {
     }
 }
 
+#[cfg(feature = "seal")]
+impl SalsaBox {
+    /// Anonymously encrypt `plaintext` to `recipient_pk`, à la libsodium's
+    /// `crypto_box_seal`.
+    ///
+    /// A fresh ephemeral X25519 keypair is generated for this call only.
+    /// Its public half is prepended to the returned ciphertext so
+    /// [`SalsaBox::unseal`] can recover it; its secret half is used to
+    /// derive a one-off [`SalsaBox`] and then dropped. The recipient can
+    /// open the message with [`SalsaBox::unseal`] but cannot learn
+    /// anything about who sent it.
+    pub fn seal<T>(
+        csprng: &mut T,
+        recipient_pk: &PublicKey,
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, Error>
+    where
+        T: RngCore + CryptoRng,
+    {
+        let mut buffer = Vec::from(plaintext);
+        let (epk_bytes, tag) = Self::seal_in_place_detached(csprng, recipient_pk, &mut buffer)?;
+
+        let mut sealed = Vec::with_capacity(KEY_SIZE + TAG_SIZE + buffer.len());
+        sealed.extend_from_slice(&epk_bytes);
+        sealed.extend_from_slice(&tag);
+        sealed.append(&mut buffer);
+        Ok(sealed)
+    }
+
+    /// In-place variant of [`SalsaBox::seal`].
+    ///
+    /// Encrypts `buffer` in place (leaving its length unchanged) and
+    /// returns the ephemeral public key and Poly1305 tag the caller is
+    /// expected to store or transmit alongside it. libsodium's combined
+    /// `crypto_box_seal` format places the tag *before* the ciphertext
+    /// (`epk || tag || ciphertext`), the opposite of this crate's own
+    /// [`Aead::encrypt_in_place`] convention, so this uses the detached
+    /// API rather than [`SalsaBox`]'s combined `Aead` impl.
+    pub fn seal_in_place_detached<T>(
+        csprng: &mut T,
+        recipient_pk: &PublicKey,
+        buffer: &mut [u8],
+    ) -> Result<([u8; KEY_SIZE], Tag), Error>
+    where
+        T: RngCore + CryptoRng,
+    {
+        let esk = SecretKey::generate(csprng);
+        let epk = esk.public_key();
+        let nonce = seal_nonce(&epk, recipient_pk);
+
+        let tag = SalsaBox::new(recipient_pk, &esk).encrypt_in_place_detached(&nonce, b"", buffer)?;
+        Ok((*epk.as_bytes(), tag))
+    }
+
+    /// Open a sealed box produced by [`SalsaBox::seal`].
+    pub fn unseal(recipient_sk: &SecretKey, sealed: &[u8]) -> Result<Vec<u8>, Error> {
+        if sealed.len() < KEY_SIZE + TAG_SIZE {
+            return Err(Error);
+        }
+
+        let (epk_bytes, rest) = sealed.split_at(KEY_SIZE);
+        let (tag_bytes, ciphertext) = rest.split_at(TAG_SIZE);
+        let mut buffer = Vec::from(ciphertext);
+        Self::unseal_in_place_detached(recipient_sk, epk_bytes, tag_bytes, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// In-place variant of [`SalsaBox::unseal`].
+    ///
+    /// `epk_bytes` is the 32-byte ephemeral public key and `tag_bytes` the
+    /// 16-byte Poly1305 tag produced by [`SalsaBox::seal_in_place_detached`];
+    /// `buffer` holds the ciphertext and is decrypted in place.
+    pub fn unseal_in_place_detached(
+        recipient_sk: &SecretKey,
+        epk_bytes: &[u8],
+        tag_bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Error> {
+        if epk_bytes.len() != KEY_SIZE || tag_bytes.len() != TAG_SIZE {
+            return Err(Error);
+        }
+
+        let mut epk_array = [0u8; KEY_SIZE];
+        epk_array.copy_from_slice(epk_bytes);
+        let epk = PublicKey::from(epk_array);
+        let nonce = seal_nonce(&epk, &recipient_sk.public_key());
+        let tag = Tag::clone_from_slice(tag_bytes);
+
+        SalsaBox::new(&epk, recipient_sk).decrypt_in_place_detached(&nonce, b"", buffer, &tag)
+    }
+}
+
+/// Derive the 24-byte sealed box nonce from the ephemeral and recipient
+/// public keys: `BLAKE2b(epk || recipient_pk)`, unkeyed, truncated to the
+/// [`XSalsa20Poly1305`] nonce size.
+#[cfg(feature = "seal")]
+fn seal_nonce(epk: &PublicKey, recipient_pk: &PublicKey) -> GenericArray<u8, U24> {
+    let mut hasher =
+        VarBlake2b::new(24).expect("24 is a valid BLAKE2b output size in bytes");
+    hasher.update(epk.as_bytes());
+    hasher.update(recipient_pk.as_bytes());
+
+    let mut nonce = GenericArray::default();
+    hasher.finalize_variable(|digest| nonce.copy_from_slice(digest));
+    nonce
+}
+
 impl Aead for SalsaBox {
     type NonceSize = U24;
     type TagSize = U16;
@@ -262,6 +523,91 @@ impl Aead for SalsaBox {
         self.0.decrypt_in_place(nonce, associated_data, buffer)
     }
 
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &GenericArray<u8, Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        self.0
+            .decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+/// Public-key encryption scheme based on the [X25519] Elliptic Curve
+/// Diffie-Hellman function and the [XChaCha20Poly1305] authenticated
+/// encryption cipher.
+///
+/// This is identical to [`SalsaBox`] except that the shared secret is
+/// passed through [`HChaCha20`] rather than `HSalsa20`, and the resulting
+/// key is used to instantiate [`XChaCha20Poly1305`] instead of
+/// `XSalsa20Poly1305`.
+///
+/// The key derivation is the same as libsodium's
+/// `crypto_box_curve25519xchacha20poly1305`, but the encryption is not:
+/// this type uses the XChaCha20Poly1305 AEAD construction, while libsodium
+/// uses a secretbox-style XChaCha20Poly1305 construction, so ciphertexts
+/// and tags produced by the two do not match.
+///
+/// This type impls the [`aead::Aead`] trait, and otherwise functions as a
+/// symmetric Authenticated Encryption with Associated Data (AEAD) cipher
+/// once instantiated.
+///
+/// [X25519]: https://cr.yp.to/ecdh.html
+/// [XChaCha20Poly1305]: https://github.com/RustCrypto/AEADs/tree/master/chacha20poly1305
+/// [`HChaCha20`]: https://docs.rs/chacha20/latest/chacha20/fn.hchacha20.html
+pub struct ChaChaBox(XChaCha20Poly1305);
+
+impl ChaChaBox {
+    /// Create a new [`ChaChaBox`], performing X25519 Diffie-Hellman to
+    /// derive a shared secret from the provided public and secret keys.
+    pub fn new(public_key: &PublicKey, secret_key: &SecretKey) -> Self {
+        let shared_secret = secret_key.0.diffie_hellman(public_key);
+
+        // Use HChaCha20 to create a uniformly random key from the shared secret
+        let key = hchacha20(
+            &GenericArray::clone_from_slice(shared_secret.as_bytes()),
+            &GenericArray::default(),
+        );
+
+        ChaChaBox(XChaCha20Poly1305::new(key))
+    }
+}
+
+impl Aead for ChaChaBox {
+    type NonceSize = U24;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+
+    fn encrypt_in_place(
+        &self,
+        nonce: &GenericArray<u8, Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut impl Buffer,
+    ) -> Result<(), Error> {
+        self.0.encrypt_in_place(nonce, associated_data, buffer)
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &GenericArray<u8, Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        self.0
+            .encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &GenericArray<u8, Self::NonceSize>,
+        associated_data: &[u8],
+        buffer: &mut impl Buffer,
+    ) -> Result<(), Error> {
+        self.0.decrypt_in_place(nonce, associated_data, buffer)
+    }
+
     fn decrypt_in_place_detached(
         &self,
         nonce: &GenericArray<u8, Self::NonceSize>,
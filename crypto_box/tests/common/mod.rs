@@ -0,0 +1,14 @@
+//! Shared helper for the known-answer tests under `tests/`.
+
+/// Decode a hex string into bytes, panicking on malformed input.
+///
+/// The test vectors in these tests come from linking directly against libsodium, so
+/// this just needs to round-trip those fixed strings; it isn't meant for
+/// anything beyond that.
+pub fn hex(s: &str) -> Vec<u8> {
+    assert_eq!(s.len() % 2, 0, "hex string must have an even length");
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("valid hex digit"))
+        .collect()
+}
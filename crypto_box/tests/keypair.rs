@@ -0,0 +1,42 @@
+//! Known-answer test for `KeyPair::from_seed` against real libsodium
+//! `crypto_box_seed_keypair` output.
+//!
+//! The vector was generated by linking against `libsodium.so.23` directly.
+//! Only the public key half is asserted against libsodium's output: X25519
+//! clamping means the secret key byte representation never matches (see
+//! the doc comment on `KeyPair::from_seed`), so this also asserts *that*
+//! the stored secret key is the clamped scalar, not the raw SHA-512 output.
+
+use crypto_box::KeyPair;
+
+mod common;
+use common::hex;
+
+fn hex32(s: &str) -> [u8; 32] {
+    hex(s).try_into().unwrap()
+}
+
+#[test]
+fn from_seed_public_key_matches_libsodium() {
+    let seed = hex32("a0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebf");
+
+    let keypair = KeyPair::from_seed(&seed);
+
+    assert_eq!(
+        *keypair.public_key.as_bytes(),
+        hex32("0ebf980a860de51ca2e0806f41f5276624ee1ae4ce239fcb72d1b028db7e391e")
+    );
+
+    // libsodium's crypto_box_seed_keypair stores the raw, unclamped
+    // SHA-512(seed)[..32] as the secret key; ours stores the X25519-clamped
+    // scalar, so the two differ even though they derive the same point.
+    let unclamped_sha512 =
+        hex32("2d5041945c4da58554a87da7f52fd15b167d20f10505bffe6eb73bc0a7fe8922");
+    assert_ne!(keypair.secret_key.to_bytes(), unclamped_sha512);
+
+    let mut clamped = unclamped_sha512;
+    clamped[0] &= 248;
+    clamped[31] &= 127;
+    clamped[31] |= 64;
+    assert_eq!(keypair.secret_key.to_bytes(), clamped);
+}
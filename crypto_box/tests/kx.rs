@@ -0,0 +1,54 @@
+//! Known-answer test for `kx::{client,server}_session_keys` against real
+//! libsodium `crypto_kx` output.
+//!
+//! The vector was generated by linking against `libsodium.so.23` directly
+//! and calling `crypto_kx_seed_keypair` and
+//! `crypto_kx_{client,server}_session_keys`.
+
+#![cfg(feature = "kx")]
+
+mod common;
+
+use common::hex;
+use crypto_box::kx::{client_session_keys, server_session_keys};
+use crypto_box::{PublicKey, SecretKey};
+
+fn hex32(s: &str) -> [u8; 32] {
+    hex(s).try_into().unwrap()
+}
+
+#[test]
+fn session_keys_match_libsodium_crypto_kx() {
+    let client_pk = PublicKey::from(hex32(
+        "57f56a5f1982c762c37291a4ec8850fb94d83a171a67c9d326ff53c6998e4825",
+    ));
+    let client_sk = SecretKey::from(hex32(
+        "441edc56cebc8e285d02267aa650819f15add7b06ef9b41b2690128dce655924",
+    ));
+    let server_pk = PublicKey::from(hex32(
+        "20243c2fa724ef40cdd0a518dd546e05b914cad065b4e8a3958079080aac6607",
+    ));
+    let server_sk = SecretKey::from(hex32(
+        "62093cd92cefd1bcb597c82ad3a8f14f9905d30b298108a8da3ecbc672cfb8dc",
+    ));
+
+    let client_keys = client_session_keys(&client_sk, &client_pk, &server_pk).unwrap();
+    let server_keys = server_session_keys(&server_sk, &server_pk, &client_pk).unwrap();
+
+    assert_eq!(
+        client_keys.rx,
+        hex32("1198a790daec925824ac7702eb028d4cc7fa8ab9b18f34534e84fc1f4556e617")
+    );
+    assert_eq!(
+        client_keys.tx,
+        hex32("136fa15aaf272ffaf449a52204eb2ef9829956162d9959b44a5f33f888139e3c")
+    );
+    assert_eq!(
+        server_keys.rx,
+        hex32("136fa15aaf272ffaf449a52204eb2ef9829956162d9959b44a5f33f888139e3c")
+    );
+    assert_eq!(
+        server_keys.tx,
+        hex32("1198a790daec925824ac7702eb028d4cc7fa8ab9b18f34534e84fc1f4556e617")
+    );
+}
@@ -0,0 +1,33 @@
+//! Known-answer test for `SalsaBox::unseal` against a real libsodium
+//! `crypto_box_seal` ciphertext.
+//!
+//! `seal()` generates a fresh ephemeral keypair on every call, so there's
+//! no fixed *sealed* output to pin against; `unseal()` is the direction
+//! that actually proves interop, since it must open a box libsodium
+//! produced. The vector below was generated by linking against
+//! `libsodium.so.23` directly and calling `crypto_box_seed_keypair` and
+//! `crypto_box_seal`.
+
+#![cfg(feature = "seal")]
+
+mod common;
+
+use common::hex;
+use crypto_box::{SalsaBox, SecretKey};
+
+#[test]
+fn unseal_matches_libsodium_crypto_box_seal() {
+    let recipient_sk: [u8; 32] = hex("77788f1a0cea001a2631dae5d05dbd062008d5b30f50b9e29beb2a7822289004")
+        .try_into()
+        .unwrap();
+    let recipient_sk = SecretKey::from(recipient_sk);
+
+    let sealed = hex(
+        "94000478b18e1377b360f502b25859640e38bd3a110ee59e1748c31c1154c9587ee2355f86\
+         5261f1497030c2556cadffdb1588999ae00ce2289b89ffa23c8b4dde5610d2b5e5d00ba3988\
+         d62d6",
+    );
+
+    let plaintext = SalsaBox::unseal(&recipient_sk, &sealed).expect("libsodium vector unseals");
+    assert_eq!(&plaintext[..], &b"Kittens are small and fluffy."[..]);
+}